@@ -15,6 +15,10 @@ pub enum Align {
 
     /// Right or bottom.
     Max,
+
+    /// An arbitrary factor in `0.0..=1.0` along the axis, for alignments that don't
+    /// fall on one of the three stops above, e.g. 30% down a panel or a golden-ratio split.
+    Fraction(f32),
 }
 
 impl Align {
@@ -44,23 +48,25 @@ impl Align {
         Self::BOTTOM
     }
 
-    /// Convert `Min => 0.0`, `Center => 0.5` or `Max => 1.0`.
+    /// Convert `Min => 0.0`, `Center => 0.5`, `Max => 1.0`, or `Fraction(f) => f`.
     #[inline(always)]
-    pub fn to_factor(self) -> f32 {
+    pub const fn to_factor(self) -> f32 {
         match self {
             Self::Min => 0.0,
             Self::Center => 0.5,
             Self::Max => 1.0,
+            Self::Fraction(f) => f,
         }
     }
 
-    /// Convert `Min => -1.0`, `Center => 0.0` or `Max => 1.0`.
+    /// Convert `Min => -1.0`, `Center => 0.0`, `Max => 1.0`, or remap `Fraction(f)` to `-1.0..=1.0`.
     #[inline(always)]
-    pub fn to_sign(self) -> f32 {
+    pub const fn to_sign(self) -> f32 {
         match self {
             Self::Min => -1.0,
             Self::Center => 0.0,
             Self::Max => 1.0,
+            Self::Fraction(f) => 2.0 * f - 1.0,
         }
     }
 
@@ -68,31 +74,47 @@ impl Align {
     /// assert_eq!(emath::Align::Min.align_size_within_range(2.0, 10.0..=20.0), 10.0..=12.0);
     /// assert_eq!(emath::Align::Center.align_size_within_range(2.0, 10.0..=20.0), 14.0..=16.0);
     /// assert_eq!(emath::Align::Max.align_size_within_range(2.0, 10.0..=20.0), 18.0..=20.0);
+    /// assert_eq!(emath::Align::Fraction(0.25).align_size_within_range(2.0, 10.0..=20.0), 12.0..=14.0);
     /// assert_eq!(emath::Align::Min.align_size_within_range(f32::INFINITY, 10.0..=20.0), 10.0..=f32::INFINITY);
     /// assert_eq!(emath::Align::Center.align_size_within_range(f32::INFINITY, 10.0..=20.0), f32::NEG_INFINITY..=f32::INFINITY);
     /// assert_eq!(emath::Align::Max.align_size_within_range(f32::INFINITY, 10.0..=20.0), f32::NEG_INFINITY..=20.0);
     /// ```
     #[inline]
-    pub fn align_size_within_range(
+    pub const fn align_size_within_range(
         self,
         size: f32,
         range: RangeInclusive<f32>,
     ) -> RangeInclusive<f32> {
         let min = *range.start();
         let max = *range.end();
-        match self {
-            Self::Min => min..=min + size,
-            Self::Center => {
-                if size == f32::INFINITY {
-                    f32::NEG_INFINITY..=f32::INFINITY
-                } else {
-                    let left = (min + max) / 2.0 - size / 2.0;
-                    left..=left + size
-                }
+        let factor = self.to_factor();
+        if size == f32::INFINITY {
+            if factor <= 0.0 {
+                min..=f32::INFINITY
+            } else if factor >= 1.0 {
+                f32::NEG_INFINITY..=max
+            } else {
+                f32::NEG_INFINITY..=f32::INFINITY
             }
-            Self::Max => max - size..=max,
+        } else {
+            let left = min + (max - min - size) * factor;
+            left..=left + size
         }
     }
+
+    /// Returns the range of the given `size` positioned so that this alignment's
+    /// edge/center lands exactly on `at`.
+    ///
+    /// ``` rust
+    /// assert_eq!(emath::Align::Min.snap(2.0, 10.0), 10.0..=12.0);
+    /// assert_eq!(emath::Align::Center.snap(2.0, 10.0), 9.0..=11.0);
+    /// assert_eq!(emath::Align::Max.snap(2.0, 10.0), 8.0..=10.0);
+    /// ```
+    #[inline]
+    pub const fn snap(self, size: f32, at: f32) -> RangeInclusive<f32> {
+        let min = at - size * self.to_factor();
+        min..=min + size
+    }
 }
 
 impl Default for Align {
@@ -104,6 +126,40 @@ impl Default for Align {
 
 // ----------------------------------------------------------------------------
 
+/// A horizontal or a vertical axis.
+///
+/// Lets layout code be written once and reused for both rows and columns,
+/// instead of duplicating a `x()`/`y()` match at every call site.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+impl Axis {
+    /// Build a [`Vec2`] from a value along this axis (`main`) and one across it (`cross`).
+    #[inline(always)]
+    pub fn vec2(self, main: f32, cross: f32) -> Vec2 {
+        match self {
+            Self::Horizontal => vec2(main, cross),
+            Self::Vertical => vec2(cross, main),
+        }
+    }
+
+    /// The range of `rect` along this axis.
+    #[inline(always)]
+    pub fn rect_range(self, rect: Rect) -> RangeInclusive<f32> {
+        match self {
+            Self::Horizontal => rect.x_range(),
+            Self::Vertical => rect.y_range(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Two-dimension alignment, e.g. [`Align2::LEFT_TOP`].
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -138,20 +194,38 @@ impl Align2 {
         vec2(self.x().to_sign(), self.y().to_sign())
     }
 
+    /// The [`Align`] for the given [`Axis`].
+    #[inline(always)]
+    pub fn on_axis(self, axis: Axis) -> Align {
+        match axis {
+            Axis::Horizontal => self.x(),
+            Axis::Vertical => self.y(),
+        }
+    }
+
     /// Used e.g. to anchor a piece of text to a part of the rectangle.
     /// Give a position within the rect, specified by the aligns
     pub fn anchor_rect(self, rect: Rect) -> Rect {
-        let x = match self.x() {
-            Align::Min => rect.left(),
-            Align::Center => rect.left() - 0.5 * rect.width(),
-            Align::Max => rect.left() - rect.width(),
-        };
-        let y = match self.y() {
-            Align::Min => rect.top(),
-            Align::Center => rect.top() - 0.5 * rect.height(),
-            Align::Max => rect.top() - rect.height(),
-        };
-        Rect::from_min_size(pos2(x, y), rect.size())
+        fn anchor(align: Align, min: f32, len: f32) -> f32 {
+            min - len * align.to_factor()
+        }
+        let pos = pos2(
+            anchor(self.x(), rect.left(), rect.width()),
+            anchor(self.y(), rect.top(), rect.height()),
+        );
+        Rect::from_min_size(pos, rect.size())
+    }
+
+    /// Returns the rectangle of the given `size` positioned so that this alignment's
+    /// corner/edge lands exactly on `point`: [`Align::Min`] puts the near edge at
+    /// `point`, [`Align::Center`] centers on it, and [`Align::Max`] puts the far edge there.
+    ///
+    /// This is the generalization of [`Self::anchor_rect`] for when you have a point
+    /// (e.g. a click position) and a desired size, rather than an existing rect to anchor to.
+    pub fn snap(self, size: Vec2, point: Pos2) -> Rect {
+        let x_range = self.x().snap(size.x, point.x);
+        let y_range = self.y().snap(size.y, point.y);
+        Rect::from_x_y_ranges(x_range, y_range)
     }
 
     /// e.g. center a size within a given frame
@@ -161,22 +235,326 @@ impl Align2 {
         Rect::from_x_y_ranges(x_range, y_range)
     }
 
+    /// Align `size` along a single `axis` of `frame`, leaving the other axis untouched.
+    pub fn align_size_on_axis(self, axis: Axis, size: f32, frame: Rect) -> Rect {
+        let range = self
+            .on_axis(axis)
+            .align_size_within_range(size, axis.rect_range(frame));
+        match axis {
+            Axis::Horizontal => Rect::from_x_y_ranges(range, frame.y_range()),
+            Axis::Vertical => Rect::from_x_y_ranges(frame.x_range(), range),
+        }
+    }
+
     pub fn pos_in_rect(self, frame: &Rect) -> Pos2 {
-        let x = match self.x() {
-            Align::Min => frame.left(),
-            Align::Center => frame.center().x,
-            Align::Max => frame.right(),
+        fn pos(align: Align, min: f32, max: f32) -> f32 {
+            min + (max - min) * align.to_factor()
+        }
+        pos2(
+            pos(self.x(), frame.left(), frame.right()),
+            pos(self.y(), frame.top(), frame.bottom()),
+        )
+    }
+}
+
+pub fn center_size_in_rect(size: Vec2, frame: Rect) -> Rect {
+    Align2::CENTER_CENTER.align_size_within_rect(size, frame)
+}
+
+// ----------------------------------------------------------------------------
+
+/// A single constraint on the length of one segment of a [`Layout`] split.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    /// An exact length, in points.
+    Fixed(f32),
+
+    /// A percentage (`0.0..=100.0`) of the available length, after spacing is removed.
+    Percentage(f32),
+
+    /// A fraction (`num / den`) of the available length, after spacing is removed.
+    Ratio { num: u32, den: u32 },
+
+    /// At least this many points. Takes up any leftover slack.
+    Min(f32),
+
+    /// At most this many points.
+    Max(f32),
+}
+
+impl Constraint {
+    fn desired(self, available: f32) -> f32 {
+        match self {
+            Self::Fixed(len) => len,
+            Self::Percentage(pct) => available * pct / 100.0,
+            Self::Ratio { num, den } => available * num as f32 / den as f32,
+            Self::Min(min) => min,
+            // Starts at zero and grows to fill leftover slack, capped by the bound.
+            Self::Max(_) => 0.0,
+        }
+    }
+
+    fn clamp(self, len: f32) -> f32 {
+        match self {
+            Self::Min(min) => len.max(min),
+            Self::Max(max) => len.min(max).max(0.0),
+            Self::Fixed(_) | Self::Percentage(_) | Self::Ratio { .. } => len,
+        }
+    }
+
+    /// Whether this segment has no length of its own to name and so should absorb
+    /// leftover slack: `Min` (unbounded) and `Max` (capped at its bound). `Fixed`,
+    /// `Percentage`, and `Ratio` name an exact length and take no part in this.
+    fn is_flexible(self) -> bool {
+        matches!(self, Self::Min(_) | Self::Max(_))
+    }
+}
+
+/// Splits a length of `total_len` into one length per `constraints`, with `spacing`
+/// subtracted between each pair of adjacent segments.
+///
+/// This is a deterministic greedy allocator, not a full LP solver: `Fixed`,
+/// `Percentage`, and `Ratio` segments are assigned their exact named length up front
+/// and take no further part in slack redistribution; any remaining slack is then
+/// distributed among the flexible (`Min`/`Max`) segments in proportion to each one's
+/// current assigned length, re-clamping against only the segments that still have
+/// room to grow (so a `Max` that already hit its bound doesn't stall the rest from
+/// absorbing what's left) and repeating until the slack is exhausted or nothing can
+/// grow any further.
+fn split_lengths(constraints: &[Constraint], total_len: f32, spacing: f32) -> Vec<f32> {
+    if constraints.is_empty() {
+        return Vec::new();
+    }
+
+    let available = (total_len - spacing * (constraints.len() as f32 - 1.0)).max(0.0);
+
+    let mut lengths: Vec<f32> = constraints
+        .iter()
+        .map(|c| c.clamp(c.desired(available)))
+        .collect();
+
+    loop {
+        let slack = available - lengths.iter().sum::<f32>();
+        if slack.abs() < f32::EPSILON {
+            break;
+        }
+
+        let growable: Vec<usize> = constraints
+            .iter()
+            .enumerate()
+            .filter(|(i, c)| c.is_flexible() && c.clamp(lengths[*i] + slack) != lengths[*i])
+            .map(|(i, _)| i)
+            .collect();
+        if growable.is_empty() {
+            break;
+        }
+
+        let weight_sum: f32 = growable.iter().map(|&i| lengths[i]).sum();
+        let growable_count = growable.len() as f32;
+        let mut changed = false;
+        for i in growable {
+            let share = if weight_sum > 0.0 {
+                slack * lengths[i] / weight_sum
+            } else {
+                slack / growable_count
+            };
+            let new_len = constraints[i].clamp(lengths[i] + share);
+            changed |= new_len != lengths[i];
+            lengths[i] = new_len;
+        }
+        if !changed {
+            break;
+        }
+    }
+
+    lengths
+}
+
+/// A declarative, tui-rs-style layout: splits a [`Rect`] (or a 1-D range) into
+/// segments along an [`Axis`], each sized according to a [`Constraint`].
+///
+/// ```
+/// use emath::{Axis, Constraint, Layout, Rect, pos2};
+/// let rect = Rect::from_min_size(pos2(0.0, 0.0), emath::vec2(100.0, 10.0));
+/// let panes = Layout::new(Axis::Horizontal)
+///     .constraints(vec![Constraint::Fixed(20.0), Constraint::Min(0.0)])
+///     .split(rect);
+/// assert_eq!(panes.len(), 2);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Layout {
+    axis: Axis,
+    spacing: f32,
+    constraints: Vec<Constraint>,
+}
+
+impl Layout {
+    pub fn new(axis: Axis) -> Self {
+        Self {
+            axis,
+            spacing: 0.0,
+            constraints: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn direction(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    #[inline]
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    #[inline]
+    pub fn constraints(mut self, constraints: impl Into<Vec<Constraint>>) -> Self {
+        self.constraints = constraints.into();
+        self
+    }
+
+    /// Split a 1-D `range` into one sub-range per constraint, in order along the range.
+    pub fn split_range(&self, range: RangeInclusive<f32>) -> Vec<RangeInclusive<f32>> {
+        let start = *range.start();
+        let lengths = split_lengths(&self.constraints, *range.end() - start, self.spacing);
+
+        let mut cursor = start;
+        lengths
+            .into_iter()
+            .map(|len| {
+                let sub_range = cursor..=cursor + len;
+                cursor += len + self.spacing;
+                sub_range
+            })
+            .collect()
+    }
+
+    /// Split `rect` into one sub-rect per constraint, laid out sequentially along [`Self`]'s axis.
+    pub fn split(&self, rect: Rect) -> Vec<Rect> {
+        self.split_range(self.axis.rect_range(rect))
+            .into_iter()
+            .map(|main_range| match self.axis {
+                Axis::Horizontal => Rect::from_x_y_ranges(main_range, rect.y_range()),
+                Axis::Vertical => Rect::from_x_y_ranges(rect.x_range(), main_range),
+            })
+            .collect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A cardinal direction, used by [`Rect::place_relative_to`] to position one rect
+/// beside another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// The [`Axis`] this direction moves along.
+    #[inline]
+    fn axis(self) -> Axis {
+        match self {
+            Self::Up | Self::Down => Axis::Vertical,
+            Self::Left | Self::Right => Axis::Horizontal,
+        }
+    }
+}
+
+impl Rect {
+    /// Positions a rect of `self`'s size beside `anchor`, offset by `gap` in the given
+    /// `dir`, and aligned on the cross axis via `cross_align`.
+    ///
+    /// E.g. place a menu [`Direction::Right`] of a button with [`Align::Center`]
+    /// alignment, or a label [`Direction::Down`] from a widget.
+    pub fn place_relative_to(
+        self,
+        anchor: Rect,
+        dir: Direction,
+        gap: f32,
+        cross_align: Align,
+    ) -> Rect {
+        let size = self.size();
+        let main_axis = dir.axis();
+        let cross_axis = match main_axis {
+            Axis::Horizontal => Axis::Vertical,
+            Axis::Vertical => Axis::Horizontal,
+        };
+
+        let main_len = match main_axis {
+            Axis::Horizontal => size.x,
+            Axis::Vertical => size.y,
+        };
+        let main_range = match dir {
+            Direction::Up | Direction::Left => {
+                let max = *main_axis.rect_range(anchor).start() - gap;
+                max - main_len..=max
+            }
+            Direction::Down | Direction::Right => {
+                let min = *main_axis.rect_range(anchor).end() + gap;
+                min..=min + main_len
+            }
         };
-        let y = match self.y() {
-            Align::Min => frame.top(),
-            Align::Center => frame.center().y,
-            Align::Max => frame.bottom(),
+
+        let cross_len = match cross_axis {
+            Axis::Horizontal => size.x,
+            Axis::Vertical => size.y,
         };
+        let cross_range =
+            cross_align.align_size_within_range(cross_len, cross_axis.rect_range(anchor));
 
-        pos2(x, y)
+        match main_axis {
+            Axis::Horizontal => Rect::from_x_y_ranges(main_range, cross_range),
+            Axis::Vertical => Rect::from_x_y_ranges(cross_range, main_range),
+        }
     }
 }
 
-pub fn center_size_in_rect(size: Vec2, frame: Rect) -> Rect {
-    Align2::CENTER_CENTER.align_size_within_rect(size, frame)
+// ----------------------------------------------------------------------------
+
+impl Rect {
+    /// The rect of cell `(row, col)` in a `rows` by `cols` grid tiling `self`, after
+    /// subtracting `row_spacing`/`col_spacing` between cells.
+    ///
+    /// Cell size is `(width - (cols - 1) * col_spacing) / cols`, and analogously for height.
+    pub fn grid_cell(
+        self,
+        row: usize,
+        col: usize,
+        rows: usize,
+        cols: usize,
+        row_spacing: f32,
+        col_spacing: f32,
+    ) -> Rect {
+        let cell_width = (self.width() - (cols as f32 - 1.0) * col_spacing) / cols as f32;
+        let cell_height = (self.height() - (rows as f32 - 1.0) * row_spacing) / rows as f32;
+        let min = self.min
+            + vec2(
+                col as f32 * (cell_width + col_spacing),
+                row as f32 * (cell_height + row_spacing),
+            );
+        Rect::from_min_size(min, vec2(cell_width, cell_height))
+    }
+
+    /// Tiles `self` into a `rows` by `cols` grid of equally-sized cells, in row-major
+    /// order, after subtracting `row_spacing`/`col_spacing` between cells.
+    ///
+    /// Useful for laying out equally-sized widgets (icon pickers, keypads, palettes)
+    /// without manually computing per-cell offsets.
+    pub fn grid(self, rows: usize, cols: usize, row_spacing: f32, col_spacing: f32) -> Vec<Rect> {
+        let mut cells = Vec::with_capacity(rows * cols);
+        for row in 0..rows {
+            for col in 0..cols {
+                cells.push(self.grid_cell(row, col, rows, cols, row_spacing, col_spacing));
+            }
+        }
+        cells
+    }
 }
@@ -0,0 +1,8 @@
+//! Opinionated 2D math library for building GUIs.
+//!
+//! Includes types for representing positions, sizes, and rectangles, along with
+//! alignment and layout helpers built on top of them.
+
+mod align;
+
+pub use align::{center_size_in_rect, Align, Align2, Axis, Constraint, Direction, Layout};